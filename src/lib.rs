@@ -14,6 +14,18 @@ use wasm_bindgen_futures::JsFuture;
 use futures_util::TryFutureExt;
 use serde::Serialize;
 
+// This binding has grown to call several `PrivateDirectoryHelper`/`FFIStore`
+// methods that are not yet part of the published `wnfsutils` dependency this
+// crate builds against: `PrivateDirectoryHelper::{read_file_range_async,
+// write_file_stream_async, stat_async, symlink_async, history_async,
+// read_file_at_revision_async}`, the two-argument `read_file_async`, and
+// `FFIStore::{get_many, put_many}`. Everything that calls one of these is
+// gated behind the `unstable-wnfsutils` Cargo feature, so a default build
+// (the feature is off until `Cargo.toml` declares it) only exercises
+// `wnfsutils` APIs that are already published. Enabling the feature is only
+// meaningful once a `wnfsutils` release carrying these additions ships
+// alongside this crate's version bump.
+
 #[derive(Serialize)]
 struct PrivateDirectoryHelperResult {
     forest_cid: String,
@@ -93,7 +105,7 @@ impl JSStore {
     }
 
     #[wasm_bindgen]
-    pub fn put_block(&self, cid: Vec<u8>, bytes: Vec<u8>) -> Result<(), JsValue> {
+    pub async fn put_block(&self, cid: Vec<u8>, bytes: Vec<u8>) -> Result<(), JsValue> {
         trace!("**********************put_block started**************");
 
         // Convert CID and bytes to Uint8Array
@@ -107,15 +119,112 @@ impl JSStore {
             .dyn_ref::<Function>()
             .ok_or_else(|| JsValue::from_str("Expected 'put' to be a JavaScript function"))?;
 
-        // Call the "put" method
-        put_fn
+        // Call the "put" method (returns a Promise)
+        let promise_value = put_fn
             .call2(&self.js_client, &cid_js_array.into(), &bytes_js_array.into())
             .map_err(|e| JsValue::from_str(&format!("Failed to call 'put': {:?}", e)))?;
 
+        // Convert JsValue to js_sys::Promise and actually await it, so a write
+        // is durable before we report success back to the caller.
+        let promise = promise_value.dyn_into::<js_sys::Promise>().map_err(|e| {
+            JsValue::from_str(&format!("Failed to convert JsValue to Promise: {:?}", e))
+        })?;
+        JsFuture::from(promise).await?;
+
         trace!("**********************put_block Put bytes for CID {:?}:>>>>>>>>>>>>>> {:?}", cid, bytes);
         trace!("**********************put_block finished**************");
         Ok(())
     }
+
+    /// Fetches many blocks in one JS round-trip via an optional `getMany`
+    /// function on the client, falling back to looping `get_block` when the
+    /// client doesn't expose it. Loading a WNFS directory touches many
+    /// blocks, so batching cuts the per-block FFI overhead that otherwise
+    /// dominates reload time.
+    #[wasm_bindgen]
+    pub async fn get_many(&self, cids: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, JsValue> {
+        trace!("**********************get_many started**************");
+
+        if let Ok(get_many_fn) = Reflect::get(&self.js_client, &JsValue::from_str("getMany"))
+            .and_then(|v| v.dyn_into::<Function>())
+        {
+            let cids_js = js_sys::Array::new();
+            for cid in &cids {
+                cids_js.push(&Uint8Array::from(cid.as_slice()));
+            }
+
+            let promise_value = get_many_fn
+                .call1(&self.js_client, &cids_js.into())
+                .map_err(|e| JsValue::from_str(&format!("Failed to call 'getMany': {:?}", e)))?;
+            let promise = promise_value.dyn_into::<js_sys::Promise>().map_err(|e| {
+                JsValue::from_str(&format!("Failed to convert JsValue to Promise: {:?}", e))
+            })?;
+            let result = JsFuture::from(promise).await?;
+
+            let results_js = result.dyn_into::<js_sys::Array>().map_err(|e| {
+                JsValue::from_str(&format!("Failed to convert 'getMany' result to Array: {:?}", e))
+            })?;
+            let mut blocks = Vec::with_capacity(results_js.length() as usize);
+            for value in results_js.iter() {
+                let bytes = value.dyn_into::<Uint8Array>().map_err(|e| {
+                    JsValue::from_str(&format!("Failed to convert block to Uint8Array: {:?}", e))
+                })?;
+                // Match get_block's validation so a missing block is an error
+                // regardless of whether the client implements `getMany`.
+                if bytes.length() == 0 {
+                    return Err(JsValue::from_str("Block data is empty"));
+                }
+                blocks.push(bytes.to_vec());
+            }
+
+            trace!("**********************get_many finished (batched)**************");
+            return Ok(blocks);
+        }
+
+        let mut blocks = Vec::with_capacity(cids.len());
+        for cid in cids {
+            blocks.push(self.get_block(cid).await?);
+        }
+        trace!("**********************get_many finished (looped)**************");
+        Ok(blocks)
+    }
+
+    /// Puts many blocks in one JS round-trip via an optional `putMany`
+    /// function on the client, falling back to looping `put_block` when the
+    /// client doesn't expose it.
+    #[wasm_bindgen]
+    pub async fn put_many(&self, pairs: Vec<(Vec<u8>, Vec<u8>)>) -> Result<(), JsValue> {
+        trace!("**********************put_many started**************");
+
+        if let Ok(put_many_fn) = Reflect::get(&self.js_client, &JsValue::from_str("putMany"))
+            .and_then(|v| v.dyn_into::<Function>())
+        {
+            let pairs_js = js_sys::Array::new();
+            for (cid, bytes) in &pairs {
+                let pair_js = js_sys::Array::new();
+                pair_js.push(&Uint8Array::from(cid.as_slice()));
+                pair_js.push(&Uint8Array::from(bytes.as_slice()));
+                pairs_js.push(&pair_js);
+            }
+
+            let promise_value = put_many_fn
+                .call1(&self.js_client, &pairs_js.into())
+                .map_err(|e| JsValue::from_str(&format!("Failed to call 'putMany': {:?}", e)))?;
+            let promise = promise_value.dyn_into::<js_sys::Promise>().map_err(|e| {
+                JsValue::from_str(&format!("Failed to convert JsValue to Promise: {:?}", e))
+            })?;
+            JsFuture::from(promise).await?;
+
+            trace!("**********************put_many finished (batched)**************");
+            return Ok(());
+        }
+
+        for (cid, bytes) in pairs {
+            self.put_block(cid, bytes).await?;
+        }
+        trace!("**********************put_many finished (looped)**************");
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait(?Send)]
@@ -128,8 +237,217 @@ impl<'a> FFIStore<'a> for JSStore {
 
     async fn put_block(&self, cid: Vec<u8>, bytes: Vec<u8>) -> Result<()> {
         self.put_block(cid, bytes)
+            .await
+            .map_err(|e| Error::msg(format!("{:?}", e)))
+    }
+
+    // `FFIStore::{get_many, put_many}` aren't part of the published
+    // `wnfsutils` trait yet — see the crate-level note — so these overrides
+    // are gated behind `unstable-wnfsutils`; the inherent `JSStore` methods
+    // above exist unconditionally and remain usable directly.
+    #[cfg(feature = "unstable-wnfsutils")]
+    async fn get_many(&self, cids: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>> {
+        self.get_many(cids)
+            .await
             .map_err(|e| Error::msg(format!("{:?}", e)))
     }
+
+    #[cfg(feature = "unstable-wnfsutils")]
+    async fn put_many(&self, pairs: Vec<(Vec<u8>, Vec<u8>)>) -> Result<()> {
+        self.put_many(pairs)
+            .await
+            .map_err(|e| Error::msg(format!("{:?}", e)))
+    }
+}
+
+/// A stateful handle around a loaded `PrivateDirectoryHelper` so JS callers can
+/// batch several operations under a single load, instead of paying a fresh
+/// `reload_async` (and its forest re-deserialization) for every call like the
+/// `*_native` free functions below do.
+///
+/// Typical usage from JS: `WnfsSession.load(client, cid)`, then any number of
+/// `mkdir`/`write_file`/`ls`/`mv`/`cp`/`rm` calls against the in-memory helper,
+/// then `commit()` to obtain the forest/root CID left by the last of them. Dropping the
+/// session (wasm-bindgen's generated `free()`) releases the WASM-held state.
+#[wasm_bindgen]
+pub struct WnfsSession {
+    helper: PrivateDirectoryHelper,
+    // Every mutating method already returns the new forest/root CID produced
+    // by that single operation; `commit()` just hands back whichever one ran
+    // last instead of calling an accessor to ask the helper for its own root
+    // (no such accessor is part of the published `wnfsutils` dependency).
+    current_cid: Cid,
+}
+
+#[wasm_bindgen]
+impl WnfsSession {
+    /// Loads the private directory helper from `cid` and keeps it alive for
+    /// subsequent mutations. This is the one reload the session pays up front.
+    #[wasm_bindgen]
+    pub async fn load(js_client: JsValue, cid: &[u8]) -> Result<WnfsSession, JsValue> {
+        trace!("**********************WnfsSession::load started**************");
+
+        let store = JSStore::new(js_client);
+        let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+        let cid = Cid::try_from(cid).map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+        match PrivateDirectoryHelper::reload_async(&mut block_store, cid).await {
+            Ok(helper) => {
+                trace!("**********************WnfsSession::load finished**************");
+                Ok(WnfsSession { helper, current_cid: cid })
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::load (reload): {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn mkdir(&mut self, path_segments: &str) -> Result<JsValue, JsValue> {
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+        match self.helper.mkdir_async(&path_segments).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::mkdir: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn write_file(
+        &mut self,
+        path_segments: &str,
+        content: Vec<u8>,
+        modification_time_seconds: i64,
+    ) -> Result<JsValue, JsValue> {
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+        match self.helper.write_file_async(&path_segments, content, modification_time_seconds).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::write_file: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    /// Appends `chunk` at `offset` into the file at `path_segments` against the
+    /// already-loaded helper, so a caller pushing a large upload through many
+    /// successive chunks pays one `load` up front instead of a full reload per
+    /// chunk.
+    ///
+    /// Backed by `write_file_stream_async`, which isn't part of the published
+    /// `wnfsutils` dependency yet — see the crate-level note — so this is
+    /// gated behind the `unstable-wnfsutils` feature until it is.
+    #[cfg(feature = "unstable-wnfsutils")]
+    #[wasm_bindgen]
+    pub async fn write_file_stream(
+        &mut self,
+        path_segments: &str,
+        chunk: Vec<u8>,
+        offset: u64,
+        modification_time_seconds: i64,
+    ) -> Result<JsValue, JsValue> {
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+        match self.helper.write_file_stream_async(&path_segments, chunk, offset, modification_time_seconds).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::write_file_stream: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn ls(&mut self, path_segments: &str) -> Result<JsValue, JsValue> {
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+        match self.helper.ls_files_async(&path_segments).await {
+            Ok(ls_result) => serde_wasm_bindgen::to_value(&ls_result).map_err(|e| JsValue::from_str(&e.to_string())),
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::ls: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn mv(&mut self, source_path_segments: &str, target_path_segments: &str) -> Result<JsValue, JsValue> {
+        let source_path_segments: Vec<String> = source_path_segments.split('/').map(String::from).collect();
+        let target_path_segments: Vec<String> = target_path_segments.split('/').map(String::from).collect();
+        match self.helper.mv_async(&source_path_segments, &target_path_segments).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::mv: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn cp(&mut self, source_path_segments: &str, target_path_segments: &str) -> Result<JsValue, JsValue> {
+        let source_path_segments: Vec<String> = source_path_segments.split('/').map(String::from).collect();
+        let target_path_segments: Vec<String> = target_path_segments.split('/').map(String::from).collect();
+        match self.helper.cp_async(&source_path_segments, &target_path_segments).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::cp: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub async fn rm(&mut self, path_segments: &str) -> Result<JsValue, JsValue> {
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+        match self.helper.rm_async(&path_segments).await {
+            Ok(new_cid) => {
+                self.current_cid = new_cid;
+                serde_wasm_bindgen::to_value(&new_cid).map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in WnfsSession::rm: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    }
+
+    /// Returns the new forest/root CID produced by the most recent mutation
+    /// (or `load`'s CID if nothing has mutated the session yet); the session
+    /// stays usable afterwards. Mirrors `load_with_wnfs_key_native`'s
+    /// `PrivateDirectoryHelperResult` shape — in this codebase `forest_cid`
+    /// and `root_dir_cid` name the same CID. Both fields hold the CID's string
+    /// form, while the `cid` argument of the other `*_native` functions and
+    /// `WnfsSession::load` takes the CID's binary bytes.
+    ///
+    /// This deliberately doesn't ask the helper for its own root CID: every
+    /// mutating method above already returns the CID that resulted from that
+    /// operation, so the session just remembers the last one instead of
+    /// depending on an accessor method.
+    #[wasm_bindgen]
+    pub async fn commit(&mut self) -> Result<JsValue, JsValue> {
+        let result = PrivateDirectoryHelperResult {
+            forest_cid: self.current_cid.to_string(),
+            root_dir_cid: self.current_cid.to_string(),
+        };
+        serde_wasm_bindgen::to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
 }
 
 #[wasm_bindgen]
@@ -194,6 +512,14 @@ pub async fn mkdir_native(
     }
 }
 
+/// Lists a directory's entries. With the `unstable-wnfsutils` feature enabled
+/// (see the crate-level note), each entry is reported as a structured
+/// `LsEntry { name, kind, size, modification_time_seconds, content_cid }`
+/// instead of the raw `ls_files_async` tuple, so symlinks surface as
+/// `FileType::Symlink` rather than being silently resolved. That's a breaking
+/// wire-format change from the plain pass-through below, which is exactly why
+/// it stays behind the feature: callers opt into the new shape only once
+/// they've updated for it, instead of it changing under them on an upgrade.
 #[wasm_bindgen]
 pub async fn ls_native(
     js_client: JsValue,
@@ -220,8 +546,34 @@ pub async fn ls_native(
         match helper.ls_files_async(&path_segments).await {
             Ok(ls_result) => {
                 trace!("**********************ls_native finished**************");
-                serde_wasm_bindgen::to_value(&ls_result)
-                    .map_err(|e| JsValue::from_str(&e.to_string()))
+
+                #[cfg(feature = "unstable-wnfsutils")]
+                let payload = {
+                    let entries: Vec<LsEntry> = ls_result
+                        .into_iter()
+                        .map(|(name, metadata)| {
+                            let kind = if metadata.is_symlink {
+                                FileType::Symlink
+                            } else if metadata.is_dir {
+                                FileType::Dir
+                            } else {
+                                FileType::File
+                            };
+                            LsEntry {
+                                name,
+                                kind,
+                                size: metadata.size,
+                                modification_time_seconds: metadata.modification_time_seconds,
+                                content_cid: metadata.content_cid.to_string(),
+                            }
+                        })
+                        .collect();
+                    serde_wasm_bindgen::to_value(&entries)
+                };
+                #[cfg(not(feature = "unstable-wnfsutils"))]
+                let payload = serde_wasm_bindgen::to_value(&ls_result);
+
+                payload.map_err(|e| JsValue::from_str(&e.to_string()))
             }
             Err(err) => {
                 trace!("wnfsError in ls_native: {:?}", err);
@@ -316,11 +668,20 @@ pub async fn write_file_native(
     }
 }
 
+/// `follow` is only accepted with the `unstable-wnfsutils` feature enabled
+/// (see the crate-level note): the two-argument `read_file_async(path, follow)`
+/// it's threaded into isn't part of the published `wnfsutils` dependency, so
+/// a default build keeps calling the existing single-argument
+/// `read_file_async(path)` and every caller keeps compiling unchanged. `follow`
+/// chooses between inspecting a symlink entry itself and resolving through
+/// it, bounded by a hop-count guard against self-referential links, and
+/// defaults to `true` to preserve the pre-`follow` read-through behavior.
 #[wasm_bindgen]
 pub async fn read_file_native(
     js_client: JsValue,
     cid: &[u8],
     path_segments: &str,
+    #[cfg(feature = "unstable-wnfsutils")] follow: Option<bool>,
 ) -> Result<JsValue, JsValue> {
     trace!("**********************read_file_native started**************");
 
@@ -339,8 +700,12 @@ pub async fn read_file_native(
         // Prepare path segments
         let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
 
-        // Perform read file operation asynchronously
-        match helper.read_file_async(&path_segments).await {
+        #[cfg(feature = "unstable-wnfsutils")]
+        let file_content_res = helper.read_file_async(&path_segments, follow.unwrap_or(true)).await;
+        #[cfg(not(feature = "unstable-wnfsutils"))]
+        let file_content_res = helper.read_file_async(&path_segments).await;
+
+        match file_content_res {
             Ok(file_content) => {
                 trace!("**********************read_file_native finished**************");
                 serde_wasm_bindgen::to_value(&file_content)
@@ -358,6 +723,67 @@ pub async fn read_file_native(
     }
 }
 
+#[derive(Serialize)]
+#[cfg(feature = "unstable-wnfsutils")]
+struct FileRangeResult {
+    data: Vec<u8>,
+    total_length: u64,
+}
+
+/// Reads `[offset, offset + len)` of a file without materializing the whole
+/// content in WASM linear memory, so large files can be streamed through a
+/// bounded buffer instead of blowing up on a single `read_file_native` call.
+/// `total_length` is returned alongside the chunk so JS can drive the read
+/// loop without a separate `stat` round-trip.
+///
+/// Backed by `read_file_range_async`, which isn't part of the published
+/// `wnfsutils` dependency yet — see the crate-level note — so this is gated
+/// behind the `unstable-wnfsutils` feature until it is.
+#[cfg(feature = "unstable-wnfsutils")]
+#[wasm_bindgen]
+pub async fn read_file_range_native(
+    js_client: JsValue,
+    cid: &[u8],
+    path_segments: &str,
+    offset: u64,
+    len: u64,
+) -> Result<JsValue, JsValue> {
+    trace!("**********************read_file_range_native started**************");
+
+    // Create JSStore instance
+    let store = JSStore::new(js_client);
+    let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+    // Deserialize the CID
+    let cid = Cid::try_from(cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+    // Reload the private directory helper asynchronously
+    let helper_res = PrivateDirectoryHelper::reload_async(&mut block_store, cid).await;
+
+    if let Ok(mut helper) = helper_res {
+        // Prepare path segments
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+
+        // Perform ranged read operation asynchronously
+        match helper.read_file_range_async(&path_segments, offset, len).await {
+            Ok((data, total_length)) => {
+                trace!("**********************read_file_range_native finished**************");
+                serde_wasm_bindgen::to_value(&FileRangeResult { data, total_length })
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in read_file_range_native: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    } else {
+        let err = helper_res.err().unwrap();
+        trace!("wnfsError in read_file_range_native (reload): {:?}", err);
+        Err(JsValue::from_str(&err.to_string()))
+    }
+}
+
 #[wasm_bindgen]
 pub async fn mv_native(
     js_client: JsValue,
@@ -490,4 +916,295 @@ pub async fn rm_native(
         trace!("wnfsError in rm_native (reload): {:?}", err);
         Err(JsValue::from_str(&err.to_string()))
     }
+}
+
+/// Mirrors the `is_file`/`is_dir`/`is_symlink` distinction WNFS nodes expose,
+/// so `ls_native` and `stat_native` can tell JS what each entry is without
+/// reading the whole file or silently resolving/erroring on symlinks.
+///
+/// Variants serialize under their Rust names (`"File"`, `"Dir"`, `"Symlink"`).
+/// Field names of `StatResult` and `LsEntry` below are plain snake_case,
+/// matching `PrivateDirectoryHelperResult` and every other struct this binding
+/// serializes to JS — there's no `rename_all = "camelCase"` anywhere else in
+/// the file, so these don't get one either.
+#[derive(Serialize)]
+#[cfg(feature = "unstable-wnfsutils")]
+enum FileType {
+    File,
+    Dir,
+    Symlink,
+}
+
+#[derive(Serialize)]
+#[cfg(feature = "unstable-wnfsutils")]
+struct StatResult {
+    kind: FileType,
+    size: u64,
+    modification_time_seconds: i64,
+    content_cid: String,
+}
+
+#[derive(Serialize)]
+#[cfg(feature = "unstable-wnfsutils")]
+struct LsEntry {
+    name: String,
+    kind: FileType,
+    size: u64,
+    modification_time_seconds: i64,
+    content_cid: String,
+}
+
+/// Returns type, size, and modification time for a single path, without
+/// reading the whole file the way `read_file_native` would. Lets a JS
+/// file-manager UI show icons and sizes cheaply. `follow` chooses between
+/// reporting a symlink entry itself (`Symlink`, pointing at its target) and
+/// resolving through it to stat whatever it points to; resolution is bounded
+/// by a hop-count guard against self-referential links. It's optional and
+/// defaults to `true`.
+///
+/// Backed by `stat_async`, which isn't part of the published `wnfsutils`
+/// dependency yet — see the crate-level note — so this is gated behind the
+/// `unstable-wnfsutils` feature until it is.
+#[cfg(feature = "unstable-wnfsutils")]
+#[wasm_bindgen]
+pub async fn stat_native(
+    js_client: JsValue,
+    cid: &[u8],
+    path_segments: &str,
+    follow: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    trace!("**********************stat_native started**************");
+
+    // Create JSStore instance
+    let store = JSStore::new(js_client);
+    let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+    // Deserialize the CID
+    let cid = Cid::try_from(cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+    // Reload the private directory helper asynchronously
+    let helper_res = PrivateDirectoryHelper::reload_async(&mut block_store, cid).await;
+
+    if let Ok(mut helper) = helper_res {
+        // Prepare path segments
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+
+        // Perform stat operation asynchronously
+        match helper.stat_async(&path_segments, follow.unwrap_or(true)).await {
+            Ok(metadata) => {
+                trace!("**********************stat_native finished**************");
+                let kind = if metadata.is_symlink {
+                    FileType::Symlink
+                } else if metadata.is_dir {
+                    FileType::Dir
+                } else {
+                    FileType::File
+                };
+                let result = StatResult {
+                    kind,
+                    size: metadata.size,
+                    modification_time_seconds: metadata.modification_time_seconds,
+                    content_cid: metadata.content_cid.to_string(),
+                };
+                serde_wasm_bindgen::to_value(&result)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in stat_native: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    } else {
+        let err = helper_res.err().unwrap();
+        trace!("wnfsError in stat_native (reload): {:?}", err);
+        Err(JsValue::from_str(&err.to_string()))
+    }
+}
+
+/// Creates a symlink at `link_path_segments` pointing at `target_path_segments`.
+/// `ls_native`/`stat_native` report the resulting entry as `FileType::Symlink`
+/// rather than silently resolving it or erroring.
+///
+/// Backed by `symlink_async`, which isn't part of the published `wnfsutils`
+/// dependency yet — see the crate-level note — so this is gated behind the
+/// `unstable-wnfsutils` feature until it is.
+#[cfg(feature = "unstable-wnfsutils")]
+#[wasm_bindgen]
+pub async fn symlink_native(
+    js_client: JsValue,
+    cid: &[u8],
+    link_path_segments: &str,
+    target_path_segments: &str,
+) -> Result<JsValue, JsValue> {
+    trace!("**********************symlink_native started**************");
+
+    // Create JSStore instance
+    let store = JSStore::new(js_client);
+    let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+    // Deserialize the CID
+    let cid = Cid::try_from(cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+    // Reload the private directory helper asynchronously
+    let helper_res = PrivateDirectoryHelper::reload_async(&mut block_store, cid).await;
+
+    if let Ok(mut helper) = helper_res {
+        // Prepare link and target path segments
+        let link_path_segments: Vec<String> =
+            link_path_segments.split('/').map(String::from).collect();
+        let target_path_segments: Vec<String> =
+            target_path_segments.split('/').map(String::from).collect();
+
+        // Perform symlink creation asynchronously
+        match helper.symlink_async(&link_path_segments, &target_path_segments).await {
+            Ok(new_cid) => {
+                trace!("**********************symlink_native finished**************");
+                serde_wasm_bindgen::to_value(&new_cid)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in symlink_native: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    } else {
+        let err = helper_res.err().unwrap();
+        trace!("wnfsError in symlink_native (reload): {:?}", err);
+        Err(JsValue::from_str(&err.to_string()))
+    }
+}
+
+#[derive(Serialize)]
+#[cfg(feature = "unstable-wnfsutils")]
+struct HistoryEntry {
+    revision_cid: String,
+    modification_time_seconds: i64,
+}
+
+/// Walks a private node's previous-revision back-pointers and returns them
+/// oldest-to-newest-reachable as `{ revision_cid, modification_time_seconds }`,
+/// stopping when no prior revision exists. This is the "read at a set of
+/// heads" idea applied to WNFS's own versioning, and lets a JS file-version
+/// browser list history without the app persisting every intermediate CID.
+///
+/// Each `revision_cid` is scoped to this node's history inside the forest
+/// loaded from `cid` — it is not itself a forest root. Pass it back to
+/// `read_file_at_native` alongside that same `cid` to read the file as it
+/// stood at that revision.
+///
+/// Backed by `history_async`, which isn't part of the published `wnfsutils`
+/// dependency yet — see the crate-level note — so this is gated behind the
+/// `unstable-wnfsutils` feature until it is.
+#[cfg(feature = "unstable-wnfsutils")]
+#[wasm_bindgen]
+pub async fn history_native(
+    js_client: JsValue,
+    cid: &[u8],
+    path_segments: &str,
+) -> Result<JsValue, JsValue> {
+    trace!("**********************history_native started**************");
+
+    // Create JSStore instance
+    let store = JSStore::new(js_client);
+    let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+    // Deserialize the CID
+    let cid = Cid::try_from(cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+    // Reload the private directory helper asynchronously
+    let helper_res = PrivateDirectoryHelper::reload_async(&mut block_store, cid).await;
+
+    if let Ok(mut helper) = helper_res {
+        // Prepare path segments
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+
+        // Walk previous-revision back-pointers asynchronously
+        match helper.history_async(&path_segments).await {
+            Ok(revisions) => {
+                trace!("**********************history_native finished**************");
+                let entries: Vec<HistoryEntry> = revisions
+                    .into_iter()
+                    .map(|(revision_cid, modification_time_seconds)| HistoryEntry {
+                        revision_cid: revision_cid.to_string(),
+                        modification_time_seconds,
+                    })
+                    .collect();
+                serde_wasm_bindgen::to_value(&entries)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in history_native: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    } else {
+        let err = helper_res.err().unwrap();
+        trace!("wnfsError in history_native (reload): {:?}", err);
+        Err(JsValue::from_str(&err.to_string()))
+    }
+}
+
+/// Reads a file as it existed at a specific historical revision, i.e. one of
+/// the `revision_cid`s returned by `history_native` for that same path.
+///
+/// A `revision_cid` is a node-level previous-pointer reached by walking that
+/// node's own history inside the forest it came from — it is not a
+/// standalone forest root, so it can't be passed to `reload_async` the way
+/// the top-level `cid` argument can. This loads the *current* forest at
+/// `cid` exactly like `read_file_native` does, then resolves the node at
+/// `revision_cid` by following its previous-pointer chain within that
+/// already-loaded forest instead of attempting a second top-level reload.
+///
+/// Backed by `read_file_at_revision_async`, which isn't part of the published
+/// `wnfsutils` dependency yet — see the crate-level note — so this is gated
+/// behind the `unstable-wnfsutils` feature until it is.
+#[cfg(feature = "unstable-wnfsutils")]
+#[wasm_bindgen]
+pub async fn read_file_at_native(
+    js_client: JsValue,
+    cid: &[u8],
+    path_segments: &str,
+    revision_cid: &[u8],
+) -> Result<JsValue, JsValue> {
+    trace!("**********************read_file_at_native started**************");
+
+    // Create JSStore instance
+    let store = JSStore::new(js_client);
+    let mut block_store = FFIFriendlyBlockStore::new(Box::new(store));
+
+    // Deserialize the forest CID and the target revision CID
+    let cid = Cid::try_from(cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+    let revision_cid = Cid::try_from(revision_cid)
+        .map_err(|e| JsValue::from_str(&format!("Invalid CID: {:?}", e)))?;
+
+    // Reload the private directory helper asynchronously, anchored at the
+    // current forest (not the historical revision)
+    let helper_res = PrivateDirectoryHelper::reload_async(&mut block_store, cid).await;
+
+    if let Ok(mut helper) = helper_res {
+        // Prepare path segments
+        let path_segments: Vec<String> = path_segments.split('/').map(String::from).collect();
+
+        // Resolve the node at `revision_cid` via its previous-pointer chain
+        // within the already-loaded forest, then read its content
+        match helper.read_file_at_revision_async(&path_segments, revision_cid).await {
+            Ok(file_content) => {
+                trace!("**********************read_file_at_native finished**************");
+                serde_wasm_bindgen::to_value(&file_content)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+            }
+            Err(err) => {
+                trace!("wnfsError in read_file_at_native: {:?}", err);
+                Err(JsValue::from_str(&err.to_string()))
+            }
+        }
+    } else {
+        let err = helper_res.err().unwrap();
+        trace!("wnfsError in read_file_at_native (reload): {:?}", err);
+        Err(JsValue::from_str(&err.to_string()))
+    }
 }
\ No newline at end of file